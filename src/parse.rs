@@ -46,14 +46,76 @@ fn extract_sign(s: &[u8]) -> (Sign, &[u8]) {
 }
 
 /// Carves off decimal digits up to the first non-digit character.
+///
+/// A single `_` is also consumed as a visual group separator, but only
+/// between two digits, so a leading, trailing, or doubled `_` simply ends
+/// the digit run rather than being swallowed.
 #[inline]
 fn eat_digits(s: &[u8]) -> (&[u8], &[u8]) {
-    let i = s.iter().take_while(|&i| i.is_ascii_digit()).count();
+    let mut i = 0;
+    let mut last_was_digit = false;
+    while i < s.len() {
+        match s[i] {
+            b'0'..=b'9' => {
+                last_was_digit = true;
+                i += 1;
+            }
+            b'_' if last_was_digit && s.get(i + 1).map_or(false, u8::is_ascii_digit) => {
+                last_was_digit = false;
+                i += 1;
+            }
+            _ => break,
+        }
+    }
     (&s[..i], &s[i..])
 }
 
+/// Counts the actual digits in a run carved off by [`eat_digits`], ignoring
+/// any `_` group separators.
+#[inline]
+fn digit_count(s: &[u8]) -> usize {
+    s.iter().filter(|&&b| b != b'_').count()
+}
+
+/// Strips leading `0` digits (and any `_` separators exposed by doing so)
+/// from a run carved off by [`eat_digits`], stopping at the first nonzero
+/// digit. At most one `0` is kept so a run of all zeros reduces to `"0"`
+/// rather than disappearing.
+#[inline]
+fn strip_leading_zeros(mut s: &[u8]) -> &[u8] {
+    loop {
+        match s.first() {
+            Some(b'0') if s.len() > 1 => s = &s[1..],
+            Some(b'_') => s = &s[1..],
+            _ => break,
+        }
+    }
+    s
+}
+
+/// Strips trailing `0` digits (and any `_` separators exposed by doing so)
+/// from a run carved off by [`eat_digits`], stopping at the first nonzero
+/// digit. Unlike [`strip_leading_zeros`] this can reduce the run to empty,
+/// since a fractional part of all zeros carries no significant digits.
+#[inline]
+fn strip_trailing_zeros(mut s: &[u8]) -> &[u8] {
+    loop {
+        match s.last() {
+            Some(b'0') | Some(b'_') => s = &s[..s.len() - 1],
+            _ => break,
+        }
+    }
+    s
+}
+
 /// Extracts exponent, if any.
-fn extract_exponent(s: &[u8]) -> Result<(i16, &[u8]), DecimalParseError> {
+///
+/// When `relaxed` is true, an exponent more negative than `-MAX_SCALE` is
+/// let through instead of being rejected outright (an exponent of more than
+/// three digits is rejected with `Overflow` either way); the caller is then
+/// expected to round or shortcut the resulting out-of-range scale itself.
+/// This is used by the lenient [`ParseOptions`]-aware parsing path.
+fn extract_exponent(s: &[u8], relaxed: bool) -> Result<(i16, &[u8]), DecimalParseError> {
     let (sign, s) = extract_sign(s);
     let (mut number, s) = eat_digits(s);
 
@@ -61,18 +123,18 @@ fn extract_exponent(s: &[u8]) -> Result<(i16, &[u8]), DecimalParseError> {
         return Err(DecimalParseError::Invalid);
     }
 
-    while number.first() == Some(&b'0') {
-        number = &number[1..];
-    }
+    number = strip_leading_zeros(number);
 
-    if number.len() > 3 {
+    if digit_count(number) > 3 {
         return Err(DecimalParseError::Overflow);
     }
 
     let exp = {
         let mut result: i16 = 0;
         for &n in number {
-            result = result * 10 + (n - b'0') as i16;
+            if n != b'_' {
+                result = result * 10 + (n - b'0') as i16;
+            }
         }
         match sign {
             Sign::Positive => result,
@@ -80,7 +142,7 @@ fn extract_exponent(s: &[u8]) -> Result<(i16, &[u8]), DecimalParseError> {
         }
     };
 
-    if exp > -MIN_SCALE || exp < -MAX_SCALE {
+    if exp > -MIN_SCALE || (!relaxed && exp < -MAX_SCALE) {
         return Err(DecimalParseError::Overflow);
     }
 
@@ -89,7 +151,9 @@ fn extract_exponent(s: &[u8]) -> Result<(i16, &[u8]), DecimalParseError> {
 
 /// Checks if the input string is a valid decimal and if so, locate the integral
 /// part, the fractional part, and the exponent in it.
-fn parse_decimal(s: &[u8]) -> Result<(Parts, &[u8]), DecimalParseError> {
+///
+/// `relaxed` is forwarded to [`extract_exponent`]; see its documentation.
+fn parse_decimal(s: &[u8], relaxed: bool) -> Result<(Parts, &[u8]), DecimalParseError> {
     let (sign, s) = extract_sign(s);
 
     if s.is_empty() {
@@ -98,9 +162,7 @@ fn parse_decimal(s: &[u8]) -> Result<(Parts, &[u8]), DecimalParseError> {
 
     let (mut integral, s) = eat_digits(s);
 
-    while integral.first() == Some(&b'0') && integral.len() > 1 {
-        integral = &integral[1..];
-    }
+    integral = strip_leading_zeros(integral);
 
     let (fractional, exp, s) = match s.first() {
         Some(&b'e') | Some(&b'E') => {
@@ -108,7 +170,7 @@ fn parse_decimal(s: &[u8]) -> Result<(Parts, &[u8]), DecimalParseError> {
                 return Err(DecimalParseError::Invalid);
             }
 
-            let (exp, s) = extract_exponent(&s[1..])?;
+            let (exp, s) = extract_exponent(&s[1..], relaxed)?;
             (&b""[..], exp, s)
         }
         Some(&b'.') => {
@@ -117,13 +179,11 @@ fn parse_decimal(s: &[u8]) -> Result<(Parts, &[u8]), DecimalParseError> {
                 return Err(DecimalParseError::Invalid);
             }
 
-            while fractional.last() == Some(&b'0') {
-                fractional = &fractional[0..fractional.len() - 1];
-            }
+            fractional = strip_trailing_zeros(fractional);
 
             match s.first() {
                 Some(&b'e') | Some(&b'E') => {
-                    let (exp, s) = extract_exponent(&s[1..])?;
+                    let (exp, s) = extract_exponent(&s[1..], relaxed)?;
                     (fractional, exp, s)
                 }
                 _ => (fractional, 0, s),
@@ -149,6 +209,62 @@ fn parse_decimal(s: &[u8]) -> Result<(Parts, &[u8]), DecimalParseError> {
     ))
 }
 
+/// Parses 8 ASCII digits at once into the integer they represent, using the
+/// SWAR (SIMD within a register) trick from Rust's `dec2flt` parser.
+///
+/// `chunk` must be exactly 8 bytes of ASCII digits; otherwise the result is
+/// meaningless, and debug builds can panic on arithmetic overflow.
+#[inline]
+fn parse_8digits(chunk: [u8; 8]) -> u64 {
+    let mut v = u64::from_le_bytes(chunk);
+    v -= 0x3030_3030_3030_3030;
+    v = (v * 10 + (v >> 8)) & 0x00FF_00FF_00FF_00FF;
+    v = (v * 100 + (v >> 16)) & 0x0000_FFFF_0000_FFFF;
+    v = (v * 10000 + (v >> 32)) & 0x0000_0000_FFFF_FFFF;
+    v
+}
+
+/// Checks whether `chunk` consists of 8 ASCII digits, per the `dec2flt`
+/// SWAR validity check.
+///
+/// Any byte from `:` upward (such as `_`) fails the check, but the bytes
+/// `*` through `/` pass it, so it is a full digit check only on runs from
+/// [`eat_digits`], which hold nothing but digits and `_`.
+#[inline]
+fn is_8digits(chunk: [u8; 8]) -> bool {
+    let v = u64::from_le_bytes(chunk);
+    (v & 0xF0F0_F0F0_F0F0_F0F0) | (((v.wrapping_add(0x0606_0606_0606_0606)) & 0xF0F0_F0F0_F0F0_F0F0))
+        == 0x3030_3030_3030_3030
+}
+
+/// Accumulates the decimal digits in `digits` into `int`, eight at a time
+/// where possible and falling back to the scalar loop for the remainder.
+#[inline]
+fn accumulate_digits(int: u128, digits: &[u8]) -> u128 {
+    let mut int = int;
+    let mut chunks = digits.chunks_exact(8);
+    for chunk in &mut chunks {
+        let chunk: [u8; 8] = chunk.try_into().unwrap();
+        if is_8digits(chunk) {
+            int = int * 100_000_000 + parse_8digits(chunk) as u128;
+        } else {
+            for &i in chunk.iter() {
+                if i != b'_' {
+                    int = int * 10 + (i - b'0') as u128;
+                }
+            }
+        }
+    }
+
+    for &i in chunks.remainder() {
+        if i != b'_' {
+            int = int * 10 + (i - b'0') as u128;
+        }
+    }
+
+    int
+}
+
 /// Carves off whitespaces up to the first non-whitespace character.
 #[inline]
 fn eat_whitespaces(s: &[u8]) -> &[u8] {
@@ -172,13 +288,118 @@ fn extract_nan(s: &[u8]) -> (bool, &[u8]) {
     }
 }
 
+/// Controls what [`ParseOptions`]-aware parsing does when a value's scale
+/// exceeds [`MAX_SCALE`], whether or not rounding it to that scale
+/// collapses the value to zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OnUnderflow {
+    /// Reject the input with [`DecimalParseError::Overflow`]. This is the
+    /// strict behavior used by [`FromStr`].
+    Error,
+    /// Round the value into [`MAX_SCALE`] digits using
+    /// [`ParseOptions::rounding`] instead of erroring; a value that rounds
+    /// away entirely becomes a (signed) zero.
+    RoundToZero,
+}
+
+/// Rounding strategy used to fit a fractional part into [`MAX_SCALE`]
+/// digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round half to even, a.k.a. banker's rounding.
+    HalfEven,
+}
+
+/// Options for the lenient parsing entry points, such as
+/// [`Decimal::from_str_rounded`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    pub on_underflow: OnUnderflow,
+    pub rounding: Rounding,
+}
+
+impl Default for ParseOptions {
+    #[inline]
+    fn default() -> Self {
+        ParseOptions {
+            on_underflow: OnUnderflow::RoundToZero,
+            rounding: Rounding::HalfEven,
+        }
+    }
+}
+
+/// Rounds off the least-significant `drop` digits of `digits` (most
+/// significant digit first, ASCII `b'0'..=b'9'`), applying `rounding` and
+/// propagating any resulting carry into the retained digits.
+///
+/// If `drop` is larger than `digits.len()`, the missing high-order digits
+/// are treated as zero, so the whole value can round away to nothing.
+fn round_excess_digits(digits: &[u8], drop: usize, rounding: Rounding) -> Vec<u8> {
+    let pad = drop.saturating_sub(digits.len());
+    let mut padded = vec![b'0'; pad];
+    padded.extend_from_slice(digits);
+
+    let kept_len = padded.len() - drop;
+    let (kept, remainder) = padded.split_at(kept_len);
+
+    let round_up = match rounding {
+        Rounding::HalfEven => match remainder.first() {
+            None => false,
+            Some(&b'5') if remainder[1..].iter().all(|&d| d == b'0') => {
+                // Exactly half: round to the nearest even digit.
+                kept.last().map_or(false, |&d| (d - b'0') % 2 == 1)
+            }
+            Some(&d) => d >= b'5',
+        },
+    };
+
+    let mut kept = kept.to_vec();
+    if round_up {
+        let mut i = kept.len();
+        loop {
+            if i == 0 {
+                kept.insert(0, b'1');
+                break;
+            }
+            i -= 1;
+            if kept[i] == b'9' {
+                kept[i] = b'0';
+            } else {
+                kept[i] += 1;
+                break;
+            }
+        }
+    }
+
+    kept
+}
+
+/// Concatenates the integral and fractional digits of a parsed number into
+/// a single owned buffer, ignoring `_` separators.
+fn concat_digits(integral: &[u8], fractional: &[u8]) -> Vec<u8> {
+    integral
+        .iter()
+        .chain(fractional.iter())
+        .copied()
+        .filter(|&b| b != b'_')
+        .collect()
+}
+
 /// Parses a string bytes and put the number into this variable.
 ///
 /// This function does not handle leading or trailing spaces, and it doesn't
 /// accept `NaN` either. It returns the remaining string bytes so that caller can
 /// check for trailing spaces/garbage if deemed necessary.
+///
+/// A `scale` that exceeds [`MAX_SCALE`] is handled according to `options`:
+/// rejected with [`OnUnderflow::Error`], or rounded half-to-even into
+/// [`MAX_SCALE`] digits (shortcutting to zero on underflow) with
+/// [`OnUnderflow::RoundToZero`], which is what [`ParseOptions::default`] uses.
 #[inline]
-fn parse_str(s: &[u8]) -> Result<(Decimal, &[u8]), DecimalParseError> {
+fn parse_str_with_options<'a>(
+    s: &'a [u8],
+    options: &ParseOptions,
+) -> Result<(Decimal, &'a [u8]), DecimalParseError> {
     let (
         Parts {
             sign,
@@ -187,33 +408,112 @@ fn parse_str(s: &[u8]) -> Result<(Decimal, &[u8]), DecimalParseError> {
             exp,
         },
         s,
-    ) = parse_decimal(s)?;
+    ) = parse_decimal(s, options.on_underflow == OnUnderflow::RoundToZero)?;
 
     let precision = if integral == &b"0"[..] {
-        fractional.len() as u32
+        digit_count(fractional) as u32
     } else {
-        (integral.len() + fractional.len()) as u32
+        (digit_count(integral) + digit_count(fractional)) as u32
     };
 
     if precision > MAX_PRECISION {
         return Err(DecimalParseError::Overflow);
     }
 
-    let scale = fractional.len() as i16 - exp;
-    if scale > MAX_SCALE || scale < MIN_SCALE {
+    let scale = digit_count(fractional) as i16 - exp;
+    if scale < MIN_SCALE {
         return Err(DecimalParseError::Overflow);
     }
 
+    if scale > MAX_SCALE {
+        if options.on_underflow == OnUnderflow::Error {
+            return Err(DecimalParseError::Overflow);
+        }
+
+        let digits = concat_digits(integral, fractional);
+        let drop = (scale - MAX_SCALE) as usize;
+        let kept = round_excess_digits(&digits, drop, options.rounding);
+
+        if kept.len() as u32 > MAX_PRECISION {
+            return Err(DecimalParseError::Overflow);
+        }
+
+        let mut int = 0u128;
+        for &d in &kept {
+            int = int * 10 + (d - b'0') as u128;
+        }
+
+        // Rounding can carry all the way through trailing zeros (e.g.
+        // `round_excess_digits(b"95", 1, HalfEven)` yields `b"10"`); strip
+        // them back off so the scale is minimal, the same way `parse_decimal`
+        // strips trailing fractional zeros and `from_str_radix` strips
+        // trailing zeros after its own rounding-free reconstruction.
+        let mut scale = MAX_SCALE;
+        while scale > 0 && int != 0 && int % 10 == 0 {
+            int /= 10;
+            scale -= 1;
+        }
+
+        // A value that rounds away entirely has no fractional digits left
+        // to justify a non-zero scale, so canonicalize the scale like any
+        // other zero. The sign is kept regardless, so the result remembers
+        // which side of zero the input underflowed from.
+        let scale = if int == 0 { 0 } else { scale };
+        let negative = sign == Sign::Negative;
+        return Ok((
+            unsafe { Decimal::from_parts_unchecked(int, scale, negative) },
+            s,
+        ));
+    }
+
     let mut int = 0u128;
+    int = accumulate_digits(int, integral);
+    int = accumulate_digits(int, fractional);
+
+    let negative = if int != 0 { sign == Sign::Negative } else { false };
+
+    Ok((
+        unsafe { Decimal::from_parts_unchecked(int, scale, negative) },
+        s,
+    ))
+}
 
-    for &i in integral {
-        int = int * 10 + (i - b'0') as u128;
+/// Parses a string bytes and put the number into this variable.
+///
+/// This function does not handle leading or trailing spaces, and it doesn't
+/// accept `NaN` either. It returns the remaining string bytes so that caller can
+/// check for trailing spaces/garbage if deemed necessary.
+#[inline]
+fn parse_str(s: &[u8]) -> Result<(Decimal, &[u8]), DecimalParseError> {
+    let (
+        Parts {
+            sign,
+            integral,
+            fractional,
+            exp,
+        },
+        s,
+    ) = parse_decimal(s, false)?;
+
+    let precision = if integral == &b"0"[..] {
+        digit_count(fractional) as u32
+    } else {
+        (digit_count(integral) + digit_count(fractional)) as u32
+    };
+
+    if precision > MAX_PRECISION {
+        return Err(DecimalParseError::Overflow);
     }
 
-    for &i in fractional {
-        int = int * 10 + (i - b'0') as u128;
+    let scale = digit_count(fractional) as i16 - exp;
+    if scale > MAX_SCALE || scale < MIN_SCALE {
+        return Err(DecimalParseError::Overflow);
     }
 
+    let mut int = 0u128;
+    int = accumulate_digits(int, integral);
+    int = accumulate_digits(int, fractional);
+
     let negative = if int != 0 {
         sign == Sign::Negative
     } else {
@@ -262,6 +562,350 @@ impl FromStr for Decimal {
     }
 }
 
+/// Parses a string slice with `options`, the `ParseOptions`-aware counterpart
+/// of [`from_str`].
+#[inline]
+fn from_str_with_options(s: &str, options: &ParseOptions) -> Result<Decimal, DecimalParseError> {
+    let s = s.as_bytes();
+    let s = eat_whitespaces(s);
+    if s.is_empty() {
+        return Err(DecimalParseError::Empty);
+    }
+
+    let (is_nan, s) = extract_nan(s);
+
+    if is_nan {
+        Err(DecimalParseError::Invalid)
+    } else {
+        let (n, s) = parse_str_with_options(s, options)?;
+
+        if s.iter().any(|n| !n.is_ascii_whitespace()) {
+            return Err(DecimalParseError::Invalid);
+        }
+
+        Ok(n)
+    }
+}
+
+/// The decomposed components of a parsed decimal string: sign, integral and
+/// fractional digits, and effective exponent.
+///
+/// A public mirror of the private `Parts`, for callers that want to inspect
+/// a decimal's significant digits and scale before committing to a
+/// [`Decimal`]. See [`Decimal::decompose`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecimalComponents<'a> {
+    pub is_negative: bool,
+    pub integral: &'a str,
+    pub fractional: &'a str,
+    pub exp: i16,
+}
+
+/// Returns the numeric value of an ASCII digit in the given `radix`
+/// (alphabet `0-9a-f`, case-insensitive), or `None` if `b` is not a valid
+/// digit in that radix.
+#[inline]
+fn digit_value(b: u8, radix: u32) -> Option<u32> {
+    let value = match b {
+        b'0'..=b'9' => (b - b'0') as u32,
+        b'a'..=b'f' => (b - b'a') as u32 + 10,
+        b'A'..=b'F' => (b - b'A') as u32 + 10,
+        _ => return None,
+    };
+    if value < radix {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Carves off digits valid in `radix` up to the first byte that isn't one.
+#[inline]
+fn eat_radix_digits(s: &[u8], radix: u32) -> (&[u8], &[u8]) {
+    let i = s
+        .iter()
+        .take_while(|&&b| digit_value(b, radix).is_some())
+        .count();
+    (&s[..i], &s[i..])
+}
+
+/// Returns `Some((a, b))` such that `radix == 2.pow(a) * 5.pow(b)`, the
+/// condition under which a base-`radix` fraction terminates in base 10.
+/// Returns `None` for a radix with another prime factor (3, 6, 7, ...),
+/// whose fractions generally repeat forever in base 10.
+fn decimal_compatible_factors(radix: u32) -> Option<(u32, u32)> {
+    let mut n = radix;
+    let mut a = 0;
+    while n % 2 == 0 {
+        n /= 2;
+        a += 1;
+    }
+    let mut b = 0;
+    while n % 5 == 0 {
+        n /= 5;
+        b += 1;
+    }
+    if n == 1 {
+        Some((a, b))
+    } else {
+        None
+    }
+}
+
+/// Counts the base-10 digits of `n` (`1` for `n == 0`).
+fn decimal_digit_count(mut n: u128) -> u32 {
+    if n == 0 {
+        return 1;
+    }
+    let mut count = 0;
+    while n > 0 {
+        n /= 10;
+        count += 1;
+    }
+    count
+}
+
+impl Decimal {
+    /// Parses a decimal value off the front of `s`, returning it together
+    /// with the unparsed remainder, so tokenizers and literal parsers can
+    /// pull a decimal off the front of a larger string without
+    /// pre-splitting it.
+    ///
+    /// Unlike [`FromStr`], the remainder is not required to be empty or
+    /// all whitespace.
+    pub fn parse_prefix(s: &str) -> Result<(Decimal, &str), DecimalParseError> {
+        let bytes = eat_whitespaces(s.as_bytes());
+        if bytes.is_empty() {
+            return Err(DecimalParseError::Empty);
+        }
+
+        let (is_nan, bytes) = extract_nan(bytes);
+        if is_nan {
+            return Err(DecimalParseError::Invalid);
+        }
+
+        let (n, rest) = parse_str(bytes)?;
+        let consumed = s.len() - rest.len();
+        Ok((n, &s[consumed..]))
+    }
+
+    /// Decomposes `s` into its sign, integral/fractional digits, and
+    /// effective exponent, without constructing a [`Decimal`].
+    pub fn decompose(s: &str) -> Result<DecimalComponents<'_>, DecimalParseError> {
+        let bytes = eat_whitespaces(s.as_bytes());
+        if bytes.is_empty() {
+            return Err(DecimalParseError::Empty);
+        }
+
+        let (is_nan, bytes) = extract_nan(bytes);
+        if is_nan {
+            return Err(DecimalParseError::Invalid);
+        }
+
+        let (
+            Parts {
+                sign,
+                integral,
+                fractional,
+                exp,
+            },
+            _,
+        ) = parse_decimal(bytes, false)?;
+
+        Ok(DecimalComponents {
+            is_negative: sign == Sign::Negative,
+            integral: std::str::from_utf8(integral).expect("integral digits are ASCII"),
+            fractional: std::str::from_utf8(fractional).expect("fractional digits are ASCII"),
+            exp,
+        })
+    }
+
+    /// Parses `s` leniently: when the exponent pushes the scale past
+    /// [`MAX_SCALE`], the digits are rounded half-to-even into that scale
+    /// (propagating carry into the integral part as needed), and a value
+    /// that underflows to nothing at that scale parses to a signed zero
+    /// instead of erroring.
+    ///
+    /// The digit count is checked against `MAX_PRECISION` before any
+    /// rounding, just as in [`FromStr`], so a fraction written out with more
+    /// digits than that is rejected rather than rounded; an exponent of more
+    /// than three digits is rejected too. Both fail with
+    /// [`DecimalParseError::Overflow`] even where the value would round to
+    /// zero. All other error conditions are identical to [`FromStr`].
+    #[inline]
+    pub fn from_str_rounded(s: &str) -> Result<Decimal, DecimalParseError> {
+        from_str_with_options(s, &ParseOptions::default())
+    }
+
+    /// Like [`Decimal::from_str_rounded`], but with explicit [`ParseOptions`].
+    #[inline]
+    pub fn parse_with_options(
+        s: &str,
+        options: &ParseOptions,
+    ) -> Result<Decimal, DecimalParseError> {
+        from_str_with_options(s, options)
+    }
+
+    /// Parses `s` as a number in the given `radix`, following the
+    /// `num-traits` convention of base-parameterized string parsing.
+    ///
+    /// A radix point (`.`) is allowed for fractional digits, but only
+    /// radices whose fractions terminate in base 10 (those of the form
+    /// `2^a * 5^b`, e.g. 2, 4, 5, 8, 10, 16) support a fractional part;
+    /// any other radix with a `.` is rejected as
+    /// [`DecimalParseError::Invalid`]. Scientific `e`/`E` exponent syntax
+    /// is unavailable for radices 15 and 16, since `e` is itself a digit
+    /// there; for every other radix the exponent is always interpreted in
+    /// base 10.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range `2..=16`.
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Decimal, DecimalParseError> {
+        assert!(
+            (2..=16).contains(&radix),
+            "radix must be in the range 2..=16, got {}",
+            radix
+        );
+
+        let bytes = eat_whitespaces(s.as_bytes());
+        if bytes.is_empty() {
+            return Err(DecimalParseError::Empty);
+        }
+
+        let (sign, bytes) = extract_sign(bytes);
+        if bytes.is_empty() {
+            return Err(DecimalParseError::Invalid);
+        }
+
+        let (mut integral, bytes) = eat_radix_digits(bytes, radix);
+
+        while integral.first() == Some(&b'0') && integral.len() > 1 {
+            integral = &integral[1..];
+        }
+
+        let (fractional, exp, bytes) = match bytes.first() {
+            Some(&b'.') => {
+                let (mut fractional, bytes) = eat_radix_digits(&bytes[1..], radix);
+                if integral.is_empty() && fractional.is_empty() {
+                    return Err(DecimalParseError::Invalid);
+                }
+
+                while fractional.last() == Some(&b'0') {
+                    fractional = &fractional[..fractional.len() - 1];
+                }
+
+                match bytes.first() {
+                    Some(&b'e') | Some(&b'E') if radix != 16 => {
+                        let (exp, bytes) = extract_exponent(&bytes[1..], false)?;
+                        (fractional, exp, bytes)
+                    }
+                    _ => (fractional, 0, bytes),
+                }
+            }
+            Some(&b'e') | Some(&b'E') if radix != 16 => {
+                if integral.is_empty() {
+                    return Err(DecimalParseError::Invalid);
+                }
+
+                let (exp, bytes) = extract_exponent(&bytes[1..], false)?;
+                (&b""[..], exp, bytes)
+            }
+            _ => {
+                if integral.is_empty() {
+                    return Err(DecimalParseError::Invalid);
+                }
+
+                (&b""[..], 0, bytes)
+            }
+        };
+
+        let bytes = eat_whitespaces(bytes);
+        if bytes.iter().any(|b| !b.is_ascii_whitespace()) {
+            return Err(DecimalParseError::Invalid);
+        }
+
+        let radix128 = radix as u128;
+        let mut integral_int = 0u128;
+        for &b in integral {
+            let digit = digit_value(b, radix).unwrap() as u128;
+            integral_int = integral_int
+                .checked_mul(radix128)
+                .and_then(|v| v.checked_add(digit))
+                .ok_or(DecimalParseError::Overflow)?;
+        }
+
+        // Express the fractional part as an exact base-10 fraction: it
+        // only terminates when `radix == 2^a * 5^b`, since those are
+        // exactly the prime factors of 10.
+        //
+        // Every multiplication, addition, and power below is checked: a
+        // well-formed but overly long input (e.g. a 40-digit base-10
+        // string, or a ~60-digit octal/binary fraction) would otherwise
+        // overflow `u128` before the `MAX_PRECISION`/`MAX_SCALE` checks
+        // further down ever run.
+        let (frac_scale, frac_int): (i16, u128) = if fractional.is_empty() {
+            (0, 0)
+        } else {
+            let (a, b) = decimal_compatible_factors(radix).ok_or(DecimalParseError::Invalid)?;
+            let n = fractional.len() as u32;
+            let scale = n.checked_mul(a.max(b)).ok_or(DecimalParseError::Overflow)?;
+
+            let mut fractional_int = 0u128;
+            for &byte in fractional {
+                let digit = digit_value(byte, radix).unwrap() as u128;
+                fractional_int = fractional_int
+                    .checked_mul(radix128)
+                    .and_then(|v| v.checked_add(digit))
+                    .ok_or(DecimalParseError::Overflow)?;
+            }
+
+            let two_pow = 2u128
+                .checked_pow(scale - a * n)
+                .ok_or(DecimalParseError::Overflow)?;
+            let five_pow = 5u128
+                .checked_pow(scale - b * n)
+                .ok_or(DecimalParseError::Overflow)?;
+            let k = fractional_int
+                .checked_mul(two_pow)
+                .and_then(|v| v.checked_mul(five_pow))
+                .ok_or(DecimalParseError::Overflow)?;
+            (scale.try_into().map_err(|_| DecimalParseError::Overflow)?, k)
+        };
+
+        let mut scale = frac_scale - exp;
+        if scale > MAX_SCALE || scale < MIN_SCALE {
+            return Err(DecimalParseError::Overflow);
+        }
+
+        let shift = 10u128
+            .checked_pow(frac_scale as u32)
+            .ok_or(DecimalParseError::Overflow)?;
+        let mut int = integral_int
+            .checked_mul(shift)
+            .and_then(|v| v.checked_add(frac_int))
+            .ok_or(DecimalParseError::Overflow)?;
+
+        // `frac_scale` is sized for the worst-case digit in `radix`, which
+        // can leave trailing zeros (e.g. "0.8" in hex needs 4 decimal
+        // places in general, but 0x8/0x10 is exactly 0.5); strip them back
+        // off so the scale is minimal, matching the decimal literal parser.
+        while scale > 0 && int % 10 == 0 {
+            int /= 10;
+            scale -= 1;
+        }
+
+        let precision = if int == 0 { 0 } else { decimal_digit_count(int) };
+        if precision > MAX_PRECISION {
+            return Err(DecimalParseError::Overflow);
+        }
+
+        let negative = if int != 0 { sign == Sign::Negative } else { false };
+
+        Ok(unsafe { Decimal::from_parts_unchecked(int, scale, negative) })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,6 +952,15 @@ mod tests {
         assert_parse_overflow("1e-131");
     }
 
+    #[test]
+    fn test_parse_underscore_error() {
+        assert_parse_invalid("_1");
+        assert_parse_invalid("1_");
+        assert_parse_invalid("1__2");
+        assert_parse_invalid("1_.2");
+        assert_parse_invalid(".._5");
+    }
+
     fn assert_parse<S: AsRef<str>, V: AsRef<str>>(s: S, expected: V) {
         let decimal = s.as_ref().parse::<Decimal>().unwrap();
         assert_eq!(decimal.to_string(), expected.as_ref());
@@ -378,5 +1031,176 @@ mod tests {
         assert_parse("-1e-10", "-0.0000000001");
         assert_parse("0000001.23456000e3", "1234.56");
         assert_parse("-0000001.23456000E-3", "-0.00123456");
+
+        // Underscore digit separators
+        assert_parse("1_234.5_6", "1234.56");
+        assert_parse("1_0e1_0", "100000000000");
+        assert_parse("-1_000_000.000_5", "-1000000.0005");
+
+        // A `_` is a transparent separator, so the minimal-scale result
+        // must not depend on where one lands relative to trailing/leading
+        // zeros: each pair below must parse identically.
+        assert_parse("2.50_0", "2.5");
+        assert_parse("2.500", "2.5");
+        assert_parse("0_10.00", "10");
+        assert_parse("010.00", "10");
+        assert_parse("1e0_0005", "100000");
+        assert_parse("1e00005", "100000");
+    }
+
+    fn accumulate_digits_scalar(digits: &[u8]) -> u128 {
+        let mut int = 0u128;
+        for &i in digits {
+            int = int * 10 + (i - b'0') as u128;
+        }
+        int
+    }
+
+    #[test]
+    fn test_from_str_radix() {
+        assert_eq!(Decimal::from_str_radix("ff", 16).unwrap().to_string(), "255");
+        assert_eq!(Decimal::from_str_radix("FF", 16).unwrap().to_string(), "255");
+        assert_eq!(Decimal::from_str_radix("-ff", 16).unwrap().to_string(), "-255");
+        assert_eq!(Decimal::from_str_radix("0.1", 2).unwrap().to_string(), "0.5");
+        assert_eq!(Decimal::from_str_radix("11", 2).unwrap().to_string(), "3");
+        assert_eq!(Decimal::from_str_radix("17", 8).unwrap().to_string(), "15");
+        assert_eq!(Decimal::from_str_radix("0.8", 16).unwrap().to_string(), "0.5");
+
+        // Scientific notation is base-10 for non-hex radices, but disabled
+        // for radix 16 since `e` is itself a hex digit there.
+        assert_eq!(Decimal::from_str_radix("1e2", 10).unwrap().to_string(), "100");
+        assert_eq!(
+            Decimal::from_str_radix("1e2", 16).unwrap().to_string(),
+            (0x1e2).to_string()
+        );
+
+        // Invalid digits for the given radix.
+        assert_eq!(
+            Decimal::from_str_radix("12", 2).unwrap_err(),
+            DecimalParseError::Invalid
+        );
+        assert_eq!(
+            Decimal::from_str_radix("", 10).unwrap_err(),
+            DecimalParseError::Empty
+        );
+
+        // A fraction that does not terminate in base 10.
+        assert_eq!(
+            Decimal::from_str_radix("0.1", 3).unwrap_err(),
+            DecimalParseError::Invalid
+        );
+
+        // More base-10 digits than MAX_PRECISION allows: the checked
+        // arithmetic in the integral accumulation must return `Overflow`
+        // instead of panicking (debug) or silently wrapping (release).
+        assert_eq!(
+            Decimal::from_str_radix("1234567890123456789012345678901234567890", 10).unwrap_err(),
+            DecimalParseError::Overflow
+        );
+
+        // A fractional part long enough (60 octal digits, 180 bits) that
+        // accumulating its digits overflows `u128` before any length-based
+        // check would otherwise catch it.
+        assert_eq!(
+            Decimal::from_str_radix(&format!("0.{}", "7".repeat(60)), 8).unwrap_err(),
+            DecimalParseError::Overflow
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be in the range 2..=16")]
+    fn test_from_str_radix_bad_radix() {
+        let _ = Decimal::from_str_radix("1", 37);
+    }
+
+    #[test]
+    fn test_parse_prefix() {
+        let (n, rest) = Decimal::parse_prefix("12.5abc").unwrap();
+        assert_eq!(n.to_string(), "12.5");
+        assert_eq!(rest, "abc");
+
+        let (n, rest) = Decimal::parse_prefix("1e3 rest").unwrap();
+        assert_eq!(n.to_string(), "1000");
+        assert_eq!(rest, " rest");
+
+        let (n, rest) = Decimal::parse_prefix("   -42   ").unwrap();
+        assert_eq!(n.to_string(), "-42");
+        assert_eq!(rest, "   ");
+    }
+
+    #[test]
+    fn test_decompose() {
+        let c = Decimal::decompose("-123.456e1").unwrap();
+        assert!(c.is_negative);
+        assert_eq!(c.integral, "123");
+        assert_eq!(c.fractional, "456");
+        assert_eq!(c.exp, 1);
+
+        let c = Decimal::decompose("128").unwrap();
+        assert!(!c.is_negative);
+        assert_eq!(c.integral, "128");
+        assert_eq!(c.fractional, "");
+        assert_eq!(c.exp, 0);
+    }
+
+    #[test]
+    fn test_round_excess_digits_half_even() {
+        // "0.5" vs "1.5" rounded to scale 0.
+        assert_eq!(round_excess_digits(b"05", 1, Rounding::HalfEven), b"0");
+        assert_eq!(round_excess_digits(b"15", 1, Rounding::HalfEven), b"2");
+
+        // Carry that increases the integral length: 9.5 -> 10.
+        assert_eq!(round_excess_digits(b"95", 1, Rounding::HalfEven), b"10");
+
+        // Not a tie: rounds based on the first dropped digit alone.
+        assert_eq!(round_excess_digits(b"124", 1, Rounding::HalfEven), b"12");
+        assert_eq!(round_excess_digits(b"126", 1, Rounding::HalfEven), b"13");
+
+        // Dropping more digits than exist rounds the whole value to zero.
+        assert_eq!(round_excess_digits(b"1", 5, Rounding::HalfEven), b"");
+    }
+
+    #[test]
+    fn test_from_str_rounded() {
+        // Values within range parse exactly like `FromStr`.
+        assert_eq!(
+            Decimal::from_str_rounded("123.456").unwrap().to_string(),
+            "123.456"
+        );
+
+        // Underflow-to-zero: too many fractional digits to represent at all,
+        // parses to a signed zero instead of `Overflow` (strict `FromStr`
+        // still errors), preserving which side of zero the input came from.
+        assert_parse_overflow("1e-131");
+        assert_eq!(Decimal::from_str_rounded("1e-131").unwrap().to_string(), "0");
+        assert_eq!(
+            Decimal::from_str_rounded("-1e-131").unwrap().to_string(),
+            "-0"
+        );
+
+        // Rounding can carry into a trailing zero ("995" rounds to "10" at
+        // the kept precision); the scale must shrink to match, not stay
+        // pinned at MAX_SCALE with a spurious trailing zero digit.
+        assert_eq!(
+            Decimal::from_str_rounded("995e-132").unwrap().to_string(),
+            format!("0.{}1", "0".repeat(128))
+        );
+    }
+
+    #[test]
+    fn test_accumulate_digits_matches_scalar() {
+        let source = b"12345678901234567890123456789012345678";
+        assert_eq!(source.len(), 38);
+
+        for len in 0..=source.len() {
+            let digits = &source[..len];
+            assert_eq!(
+                accumulate_digits(0, digits),
+                accumulate_digits_scalar(digits),
+                "mismatch for {} digits: {:?}",
+                len,
+                std::str::from_utf8(digits).unwrap()
+            );
+        }
     }
 }